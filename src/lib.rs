@@ -95,9 +95,12 @@
 )]
 
 use std::collections::HashMap;
-use tide::http::Method;
+use std::future::Future;
+use std::str::FromStr;
+use std::sync::Arc;
+use tide::http::{Method, StatusCode};
 use tide::utils::async_trait;
-use tide::{Endpoint, Middleware};
+use tide::{Endpoint, Middleware, Next};
 
 struct BoxedEndpoint<State>(Box<dyn Endpoint<State>>);
 
@@ -115,22 +118,88 @@ impl<State: Clone + Send + Sync + 'static> Endpoint<State> for BoxedEndpoint<Sta
     }
 }
 
+/// Wraps a shared, already-boxed `Middleware` so it can be handed to Tide's own per-route
+/// `Route::with`, which is what actually builds the `Next` chain for a route (its fields are
+/// private to the `tide` crate, so we can't build one ourselves).
+struct SharedMiddleware<State>(Arc<dyn Middleware<State>>);
+
+#[async_trait]
+impl<State: Clone + Send + Sync + 'static> Middleware<State> for SharedMiddleware<State> {
+    async fn handle(&self, request: tide::Request<State>, next: Next<'_, State>) -> tide::Result {
+        self.0.handle(request, next).await
+    }
+
+    fn name(&self) -> &str {
+        self.0.name()
+    }
+}
+
+/// Around-style middleware synthesized from a "before" request transform: run `before`, then
+/// call through to the rest of the chain.
+struct BeforeMiddleware<F>(F);
+
+#[async_trait]
+impl<State, F, Fut> Middleware<State> for BeforeMiddleware<F>
+where
+    State: Clone + Send + Sync + 'static,
+    F: Fn(tide::Request<State>) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = tide::Request<State>> + Send + 'static,
+{
+    async fn handle(&self, req: tide::Request<State>, next: Next<'_, State>) -> tide::Result {
+        let req = (self.0)(req).await;
+        Ok(next.run(req).await)
+    }
+}
+
+/// Around-style middleware synthesized from an "after" response transform: call through to the
+/// rest of the chain, then run `after` on the resulting response.
+struct AfterMiddleware<F>(F);
+
+#[async_trait]
+impl<State, F, Fut> Middleware<State> for AfterMiddleware<F>
+where
+    State: Clone + Send + Sync + 'static,
+    F: Fn(tide::Response) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = tide::Response> + Send + 'static,
+{
+    async fn handle(&self, req: tide::Request<State>, next: Next<'_, State>) -> tide::Result {
+        let res = next.run(req).await;
+        Ok((self.0)(res).await)
+    }
+}
+
 /// A router is any component where routes can be registered.
 pub trait Router<State: Clone + Send + Sync + 'static> {
-    /// Register a single endpoint on the `Router`
-    fn register_endpoint(&mut self, path: &str, method: Method, endpoint: impl Endpoint<State>);
+    /// Register a single endpoint, wrapped by its middleware stack, on the `Router`
+    fn register_endpoint(
+        &mut self,
+        path: &str,
+        method: Method,
+        middleware: &[Arc<dyn Middleware<State>>],
+        endpoint: impl Endpoint<State>,
+    );
 
     /// Register all routes from a RouteBuilder on the `Router`
     fn register(&mut self, routes: RouteBuilder<State>) {
-        for EndpointDescriptor(path, _middleware, method, endpoint) in routes.build() {
-            self.register_endpoint(&path, method, endpoint)
+        for EndpointDescriptor(path, middleware, method, endpoint) in routes.build() {
+            self.register_endpoint(&path, method, &middleware, endpoint)
         }
     }
 }
 
 impl<State: Clone + Send + Sync + 'static> Router<State> for tide::Server<State> {
-    fn register_endpoint(&mut self, path: &str, method: Method, endpoint: impl Endpoint<State>) {
-        self.at(path).method(method, endpoint);
+    fn register_endpoint(
+        &mut self,
+        path: &str,
+        method: Method,
+        middleware: &[Arc<dyn Middleware<State>>],
+        endpoint: impl Endpoint<State>,
+    ) {
+        let mut route = self.at(path);
+        for mw in middleware {
+            route.with(SharedMiddleware(mw.clone()));
+        }
+        route.method(method, endpoint);
     }
 }
 
@@ -159,9 +228,83 @@ impl<State: Clone + Send + Sync + 'static> RouteBuilder<State> {
         self.add_branch(RouteSegment::Path(path.to_string()), routes)
     }
 
+    /// Add sub-routes for a named path parameter, rendering as `:name` in the registered route.
+    pub fn at_param<R: Fn(Self) -> Self>(self, name: &str, routes: R) -> Self {
+        self.add_branch(RouteSegment::Param(name.to_string()), routes)
+    }
+
+    /// Add a trailing catch-all segment for a named path parameter, rendering as `*name`. A
+    /// wildcard segment must be terminal, since Tide only allows it at the end of a route.
+    pub fn at_wildcard<R: Fn(Self) -> Self>(mut self, name: &str, routes: R) -> Self {
+        let branch = routes(RouteBuilder {
+            route: RouteSegment::Wildcard(name.to_string()),
+            branches: Vec::new(),
+            endpoints: HashMap::new(),
+        });
+        debug_assert!(
+            branch.branches.is_empty(),
+            "a wildcard segment is terminal and cannot have child branches"
+        );
+        self.branches.push(branch);
+        self
+    }
+
     /// Add sub-routes for a middleware
     pub fn with<M: Middleware<State>, R: Fn(Self) -> Self>(self, middleware: M, routes: R) -> Self {
-        self.add_branch(RouteSegment::Middleware(Box::new(middleware)), routes)
+        self.add_branch(RouteSegment::Middleware(Arc::new(middleware)), routes)
+    }
+
+    /// Add sub-routes wrapped by a middleware that only transforms the incoming request, e.g.
+    /// for header injection or logging, without the boilerplate of the full around-style
+    /// `Middleware` signature.
+    pub fn before<F, Fut, R>(self, before: F, routes: R) -> Self
+    where
+        F: Fn(tide::Request<State>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = tide::Request<State>> + Send + 'static,
+        R: Fn(Self) -> Self,
+    {
+        self.with(BeforeMiddleware(before), routes)
+    }
+
+    /// Add sub-routes wrapped by a middleware that only transforms the outgoing response,
+    /// without the boilerplate of the full around-style `Middleware` signature.
+    pub fn after<F, Fut, R>(self, after: F, routes: R) -> Self
+    where
+        F: Fn(tide::Response) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = tide::Response> + Send + 'static,
+        R: Fn(Self) -> Self,
+    {
+        self.with(AfterMiddleware(after), routes)
+    }
+
+    /// Mount an already-built `RouteBuilder` under a path segment, letting large apps define a
+    /// route group such as `admin` in its own module or crate and graft it onto the tree.
+    pub fn mount(mut self, path: &str, sub: RouteBuilder<State>) -> Self {
+        self.branches.push(RouteBuilder {
+            route: RouteSegment::Path(path.to_string()),
+            branches: vec![sub],
+            endpoints: HashMap::new(),
+        });
+        self
+    }
+
+    /// Mount an already-built `RouteBuilder` under a path segment, scoped by a middleware.
+    pub fn mount_with<M: Middleware<State>>(
+        mut self,
+        path: &str,
+        middleware: M,
+        sub: RouteBuilder<State>,
+    ) -> Self {
+        self.branches.push(RouteBuilder {
+            route: RouteSegment::Path(path.to_string()),
+            branches: vec![RouteBuilder {
+                route: RouteSegment::Middleware(Arc::new(middleware)),
+                branches: vec![sub],
+                endpoints: HashMap::new(),
+            }],
+            endpoints: HashMap::new(),
+        });
+        self
     }
 
     fn add_branch<R: Fn(Self) -> Self>(mut self, spec: RouteSegment<State>, routes: R) -> Self {
@@ -179,21 +322,88 @@ impl<State: Clone + Send + Sync + 'static> RouteBuilder<State> {
         self
     }
 
+    /// List the routes this `RouteBuilder` will register: their fully-resolved path, HTTP
+    /// method and middleware stack. Useful for printing a startup route map, asserting in tests
+    /// that a path/method is registered with the expected middleware, or feeding an
+    /// OpenAPI/route-listing generator, without registering against a live `tide::Server`. Takes
+    /// `&self` so the same `RouteBuilder` can still be passed to `register` afterwards.
+    pub fn routes(&self) -> Vec<RouteInfo> {
+        self.routes_from(String::new(), &[])
+    }
+
+    /// Recursively walk the tree by reference, threading the accumulated path prefix and
+    /// middleware stack down to each leaf endpoint.
+    fn routes_from(
+        &self,
+        prefix: String,
+        middleware: &[Arc<dyn Middleware<State>>],
+    ) -> Vec<RouteInfo> {
+        let mut middleware = middleware.to_vec();
+        let prefix = match &self.route {
+            RouteSegment::Root => prefix,
+            RouteSegment::Path(segment) => format!("{}/{}", prefix, segment),
+            RouteSegment::Param(name) => format!("{}/:{}", prefix, name),
+            RouteSegment::Wildcard(name) => format!("{}/*{}", prefix, name),
+            RouteSegment::Middleware(mw) => {
+                middleware.push(mw.clone());
+                prefix
+            }
+        };
+
+        let local_routes = self.endpoints.keys().map(|method| RouteInfo {
+            path: prefix.clone(),
+            method: method.clone(),
+            middleware: middleware.iter().map(|mw| mw.name().to_string()).collect(),
+        });
+
+        let sub_routes = self
+            .branches
+            .iter()
+            .flat_map(|branch| branch.routes_from(prefix.clone(), &middleware));
+
+        local_routes.chain(sub_routes).collect()
+    }
+
     fn build(self) -> impl Iterator<Item = EndpointDescriptor<State>> {
-        let local_endpoints: Vec<EndpointDescriptor<State>> = self.endpoints.into_iter().map(|(method, endpoint)| {
-            EndpointDescriptor(String::new(), Vec::new(), method, endpoint)
-        }).collect();
+        self.build_from(String::new(), Vec::new()).into_iter()
+    }
+
+    /// Recursively walk the tree, threading the accumulated path prefix and middleware stack
+    /// down to each leaf endpoint.
+    fn build_from(
+        self,
+        prefix: String,
+        middleware: Vec<Arc<dyn Middleware<State>>>,
+    ) -> Vec<EndpointDescriptor<State>> {
+        let (prefix, middleware) = match self.route {
+            RouteSegment::Root => (prefix, middleware),
+            RouteSegment::Path(segment) => (format!("{}/{}", prefix, segment), middleware),
+            RouteSegment::Param(name) => (format!("{}/:{}", prefix, name), middleware),
+            RouteSegment::Wildcard(name) => (format!("{}/*{}", prefix, name), middleware),
+            RouteSegment::Middleware(mw) => {
+                let mut middleware = middleware;
+                middleware.push(mw);
+                (prefix, middleware)
+            }
+        };
 
-        let sub_endpoints: Vec<EndpointDescriptor<State>> = self.branches.into_iter().flat_map(RouteBuilder::build).collect();
+        let local_endpoints = self.endpoints.into_iter().map(|(method, endpoint)| {
+            EndpointDescriptor(prefix.clone(), middleware.clone(), method, endpoint)
+        });
 
-        local_endpoints.into_iter().chain(sub_endpoints.into_iter())
+        let sub_endpoints = self
+            .branches
+            .into_iter()
+            .flat_map(|branch| branch.build_from(prefix.clone(), middleware.clone()));
+
+        local_endpoints.chain(sub_endpoints).collect()
     }
 }
 
 /// Describes an endpoint, the path to it, its middleware and its HttpMethod
 struct EndpointDescriptor<State>(
     String,
-    Vec<Box<dyn Middleware<State>>>,
+    Vec<Arc<dyn Middleware<State>>>,
     Method,
     BoxedEndpoint<State>,
 );
@@ -201,12 +411,52 @@ struct EndpointDescriptor<State>(
 enum RouteSegment<State> {
     Root,
     Path(String),
-    Middleware(Box<dyn Middleware<State>>),
+    Param(String),
+    Wildcard(String),
+    Middleware(Arc<dyn Middleware<State>>),
+}
+
+/// Describes a single resolved route, as returned by [`RouteBuilder::routes`].
+#[derive(Debug)]
+pub struct RouteInfo {
+    /// The fully-resolved path of the route, e.g. `/api/v1/users/:id`.
+    pub path: String,
+    /// The HTTP method the route is registered for.
+    pub method: Method,
+    /// The ordered names of the middleware wrapping this route, as returned by
+    /// `Middleware::name`.
+    pub middleware: Vec<String>,
+}
+
+/// Extension trait for pulling typed path parameters out of a `tide::Request` registered via
+/// [`RouteBuilder::at_param`] or [`RouteBuilder::at_wildcard`].
+pub trait RequestParamExt {
+    /// Parse the named path parameter into `T`, failing with a `400 Bad Request` error if the
+    /// parameter is missing or doesn't parse into `T`.
+    fn param_as<T: FromStr>(&self, name: &str) -> tide::Result<T>;
+}
+
+impl<State> RequestParamExt for tide::Request<State> {
+    fn param_as<T: FromStr>(&self, name: &str) -> tide::Result<T> {
+        let value = self.param(name).map_err(|_| {
+            tide::Error::from_str(
+                StatusCode::BadRequest,
+                format!("missing path parameter `{name}`"),
+            )
+        })?;
+
+        value.parse().map_err(|_| {
+            tide::Error::from_str(
+                StatusCode::BadRequest,
+                format!("invalid path parameter `{name}`"),
+            )
+        })
+    }
 }
 
 /// Import types to use tide_fluent_routes
 pub mod prelude {
-    pub use super::{Router, root, RouteBuilder};
+    pub use super::{root, RequestParamExt, RouteBuilder, RouteInfo, Router};
     pub use tide::http::Method;
 }
 